@@ -0,0 +1,170 @@
+//! PCI device class definitions and name lookups against the embedded
+//! `pci.ids` database (see [`crate::extra::PCI_IDS`]).
+
+/// A top-level PCI device class, as defined by the `pci.ids` database.
+///
+/// Used with [`crate::Fetch::fetch_by_class`] to filter devices down to a
+/// single class, e.g. [`DeviceClass::DisplayController`] for GPUs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DeviceClass {
+    Unclassified,
+    MassStorageController,
+    NetworkController,
+    DisplayController,
+    MultimediaController,
+    Bridge,
+    SerialBusController,
+    Other(u8),
+}
+
+impl DeviceClass {
+    /// Returns the single-byte PCI class code this variant represents.
+    pub fn id(&self) -> u8 {
+        match self {
+            DeviceClass::Unclassified => 0x00,
+            DeviceClass::MassStorageController => 0x01,
+            DeviceClass::NetworkController => 0x02,
+            DeviceClass::DisplayController => 0x03,
+            DeviceClass::MultimediaController => 0x04,
+            DeviceClass::Bridge => 0x06,
+            DeviceClass::SerialBusController => 0x0c,
+            DeviceClass::Other(id) => *id,
+        }
+    }
+}
+
+/// Splits an un-indented `pci.ids` entry (`"03  Display controller"`) into
+/// its hex id and name.
+fn parse_entry(line: &str) -> Option<(u8, &str)> {
+    let mut parts = line.trim_start().splitn(2, char::is_whitespace);
+    let id = u8::from_str_radix(parts.next()?, 16).ok()?;
+    Some((id, parts.next().unwrap_or_default().trim()))
+}
+
+/// Looks up the human-readable name of the top-level class `class_id`
+/// belongs to, e.g. `0x03` resolves to `"Display controller"`.
+///
+/// Returns an empty `String` if `pci.ids` has no entry for the class.
+pub(crate) fn class_name(class_id: u8) -> String {
+    for line in crate::extra::PCI_IDS.lines() {
+        if let Some(rest) = line.strip_prefix("C ") {
+            if let Some((id, name)) = parse_entry(rest) {
+                if id == class_id {
+                    return name.to_string();
+                }
+            }
+        }
+    }
+    String::new()
+}
+
+/// Looks up the human-readable name of the subclass `subclass_id` under
+/// `class_id`, e.g. class `0x03`, subclass `0x00` resolves to
+/// `"VGA compatible controller"`.
+///
+/// Returns an empty `String` if `pci.ids` has no entry for the subclass.
+pub(crate) fn subclass_name(class_id: u8, subclass_id: u8) -> String {
+    let mut in_class = false;
+
+    for line in crate::extra::PCI_IDS.lines() {
+        if let Some(rest) = line.strip_prefix("C ") {
+            in_class = parse_entry(rest).map(|(id, _)| id) == Some(class_id);
+            continue;
+        }
+        if !in_class || line.starts_with("\t\t") {
+            continue;
+        }
+        if let Some(rest) = line.strip_prefix('\t') {
+            if let Some((id, name)) = parse_entry(rest) {
+                if id == subclass_id {
+                    return name.to_string();
+                }
+            }
+        }
+    }
+    String::new()
+}
+
+/// Looks up the human-readable name of the programming interface
+/// `prog_if_id` under `class_id`/`subclass_id`, e.g. class `0x01`, subclass
+/// `0x06`, prog-if `0x01` resolves to `"AHCI interface"`.
+///
+/// Returns an empty `String` if `pci.ids` has no entry for the prog-if.
+pub(crate) fn prog_if_name(class_id: u8, subclass_id: u8, prog_if_id: u8) -> String {
+    let mut in_class = false;
+    let mut in_subclass = false;
+
+    for line in crate::extra::PCI_IDS.lines() {
+        if let Some(rest) = line.strip_prefix("C ") {
+            in_class = parse_entry(rest).map(|(id, _)| id) == Some(class_id);
+            in_subclass = false;
+            continue;
+        }
+        if !in_class {
+            continue;
+        }
+        if let Some(rest) = line.strip_prefix("\t\t") {
+            if in_subclass {
+                if let Some((id, name)) = parse_entry(rest) {
+                    if id == prog_if_id {
+                        return name.to_string();
+                    }
+                }
+            }
+            continue;
+        }
+        if let Some(rest) = line.strip_prefix('\t') {
+            in_subclass = parse_entry(rest).map(|(id, _)| id) == Some(subclass_id);
+        }
+    }
+    String::new()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn class_name_resolves_known_classes() {
+        assert_eq!(class_name(0x03), "Display controller");
+        assert_eq!(class_name(0x0c), "Serial bus controller");
+    }
+
+    #[test]
+    fn class_name_falls_back_to_empty_string() {
+        assert_eq!(class_name(0xff), "");
+    }
+
+    #[test]
+    fn subclass_name_resolves_known_subclasses() {
+        assert_eq!(subclass_name(0x03, 0x00), "VGA compatible controller");
+        assert_eq!(subclass_name(0x01, 0x06), "SATA controller");
+    }
+
+    #[test]
+    fn subclass_name_does_not_leak_across_classes() {
+        // `0x00` is a subclass of several classes, with a different name in
+        // each; the lookup must use the one under the requested class.
+        assert_eq!(subclass_name(0x01, 0x00), "SCSI storage controller");
+        assert_eq!(subclass_name(0x02, 0x00), "Ethernet controller");
+    }
+
+    #[test]
+    fn subclass_name_falls_back_to_empty_string() {
+        assert_eq!(subclass_name(0x03, 0xff), "");
+        assert_eq!(subclass_name(0xff, 0x00), "");
+    }
+
+    #[test]
+    fn prog_if_name_resolves_known_prog_ifs() {
+        assert_eq!(prog_if_name(0x01, 0x06, 0x01), "AHCI interface");
+        assert_eq!(prog_if_name(0x01, 0x06, 0x00), "Vanilla SATA controller");
+    }
+
+    #[test]
+    fn prog_if_name_falls_back_to_empty_string() {
+        assert_eq!(prog_if_name(0x01, 0x06, 0xff), "");
+        assert_eq!(prog_if_name(0x01, 0xff, 0x00), "");
+        assert_eq!(prog_if_name(0xff, 0x06, 0x00), "");
+    }
+}
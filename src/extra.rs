@@ -0,0 +1,212 @@
+//! Small helpers shared by every platform backend: reading `sysfs`-style
+//! attribute files, converting between the `Vec<u8>` ID representation and
+//! hex strings, and tidying up vendor/device names for `Fetch::fetch_gpus()`.
+
+use std::fs;
+use std::path::Path;
+
+/// The embedded `pci.ids` database, used to resolve vendor, device and class
+/// names without needing `pciutils` installed on the host.
+pub(crate) const PCI_IDS: &str = include_str!("pci.ids");
+
+/// Reads the contents of `path` and trims surrounding whitespace.
+///
+/// Returns an empty `String` if the file doesn't exist or can't be read
+/// (missing attribute, insufficient permissions, device removed, etc.)
+/// rather than propagating an error, since most callers just want a
+/// best-effort value to populate a field with.
+pub(crate) fn read_attribute(path: &Path) -> String {
+    fs::read_to_string(path).unwrap_or_default().trim().to_string()
+}
+
+/// Parses a `sysfs` hex attribute (e.g. `"0x10de"` or `"10de"`) into its raw
+/// bytes, e.g. `"0x10de"` becomes `vec![0x10, 0xde]`.
+///
+/// An odd number of hex digits is left-padded with a `0` nibble so every
+/// byte is fully represented. Unparseable input yields an empty `Vec`.
+pub(crate) fn hex_str_to_bytes(s: &str) -> Vec<u8> {
+    let s = s.trim().trim_start_matches("0x");
+    if s.is_empty() {
+        return Vec::new();
+    }
+
+    let padded = if !s.len().is_multiple_of(2) {
+        format!("0{}", s)
+    } else {
+        s.to_string()
+    };
+
+    padded
+        .as_bytes()
+        .chunks(2)
+        .map(|pair| u8::from_str_radix(std::str::from_utf8(pair).ok()?, 16).ok())
+        .collect::<Option<Vec<u8>>>()
+        .unwrap_or_default()
+}
+
+/// Renders a `Vec<u8>` ID as the canonical lowercase hex string `lspci` and
+/// `pci.ids` use, e.g. `[0x10, 0xde]` becomes `"10de"`.
+pub(crate) fn bytes_to_hex_string(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+/// Parses a big-endian `Vec<u8>` ID into a `u16`, e.g. `[0x10, 0xde]`
+/// becomes `0x10de`.
+pub(crate) fn bytes_to_u16(bytes: &[u8]) -> u16 {
+    bytes.iter().fold(0u16, |acc, &byte| (acc << 8) | byte as u16)
+}
+
+/// Parses a big-endian `Vec<u8>` ID into a `u32`, e.g.
+/// `[0x03, 0x00, 0x00]` becomes `0x030000`.
+pub(crate) fn bytes_to_u32(bytes: &[u8]) -> u32 {
+    bytes.iter().fold(0u32, |acc, &byte| (acc << 8) | byte as u32)
+}
+
+/// Parses the bus and device numbers out of a `bb:dd.f` PCI address and
+/// combines them as `(bus << 8) | device`, the ID GPU-compute tooling uses
+/// to key a device.
+pub(crate) fn address_to_pci_id(address: &str) -> u16 {
+    let mut parts = address.rsplit(':');
+    let device_function = parts.next().unwrap_or_default();
+    let bus = parts.next().unwrap_or_default();
+    let device = device_function.split('.').next().unwrap_or_default();
+
+    let bus = u16::from_str_radix(bus, 16).unwrap_or(0);
+    let device = u16::from_str_radix(device, 16).unwrap_or(0);
+
+    (bus << 8) | device
+}
+
+/// Strips manufacturer boilerplate out of a vendor name, e.g.
+/// `"NVIDIA Corporation"` becomes `"NVIDIA"`.
+pub(crate) fn mask_vendor_name(name: &str) -> String {
+    name.split(['[', '('])
+        .next()
+        .unwrap_or(name)
+        .replace("Corporation", "")
+        .replace("Technology Inc.", "")
+        .replace("Advanced Micro Devices, Inc.", "AMD")
+        .trim()
+        .to_string()
+}
+
+/// Strips the chip codename out of a device name, keeping only the
+/// marketing name in brackets, e.g.
+/// `"TU117M [GeForce GTX 1650 Mobile / Max-Q]"` becomes
+/// `"GeForce GTX 1650 Mobile / Max-Q"`.
+pub(crate) fn mask_device_name(name: &str) -> String {
+    if let (Some(start), Some(end)) = (name.find('['), name.rfind(']')) {
+        name[start + 1..end].trim().to_string()
+    } else {
+        name.trim().to_string()
+    }
+}
+
+/// Splits a `pci.ids` entry line (leading tabs are ignored) into its
+/// `(id, name)` parts, e.g. `"10de  NVIDIA Corporation"` becomes
+/// `("10de", "NVIDIA Corporation")`.
+fn split_id_line(line: &str) -> Option<(String, String)> {
+    let line = line.trim_start();
+    let mut parts = line.splitn(2, char::is_whitespace);
+    let id = parts.next()?.to_lowercase();
+    let name = parts.next()?.trim().to_string();
+    Some((id, name))
+}
+
+/// Looks up a vendor's name from its `vendor_id` bytes, e.g. `[0x10, 0xde]`
+/// resolves to `"NVIDIA Corporation"`.
+///
+/// Returns an empty `String` if `pci.ids` has no entry for the vendor.
+pub(crate) fn vendor_name(vendor_id: &[u8]) -> String {
+    let target = bytes_to_hex_string(vendor_id);
+    for line in PCI_IDS.lines() {
+        if line.starts_with('\t') || line.starts_with('C') || line.starts_with('#') || line.trim().is_empty() {
+            continue;
+        }
+        if let Some((id, name)) = split_id_line(line) {
+            if id == target {
+                return name;
+            }
+        }
+    }
+    String::new()
+}
+
+/// Looks up a device's name from its `vendor_id`/`device_id` bytes, e.g.
+/// vendor `[0x10, 0xde]` and device `[0x13, 0x81]` resolves to
+/// `"GK208 [GeForce GT 730]"`.
+///
+/// Returns an empty `String` if `pci.ids` has no entry for the device.
+pub(crate) fn device_name(vendor_id: &[u8], device_id: &[u8]) -> String {
+    let vendor_target = bytes_to_hex_string(vendor_id);
+    let device_target = bytes_to_hex_string(device_id);
+    let mut in_vendor = false;
+
+    for line in PCI_IDS.lines() {
+        if line.starts_with('C') {
+            in_vendor = false;
+            continue;
+        }
+        if !line.starts_with('\t') {
+            in_vendor = !line.starts_with('#')
+                && !line.trim().is_empty()
+                && split_id_line(line).map(|(id, _)| id == vendor_target).unwrap_or(false);
+            continue;
+        }
+        if in_vendor && !line.starts_with("\t\t") {
+            if let Some((id, name)) = split_id_line(line) {
+                if id == device_target {
+                    return name;
+                }
+            }
+        }
+    }
+    String::new()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hex_str_to_bytes_parses_with_and_without_prefix() {
+        assert_eq!(hex_str_to_bytes("0x10de"), vec![0x10, 0xde]);
+        assert_eq!(hex_str_to_bytes("10de"), vec![0x10, 0xde]);
+    }
+
+    #[test]
+    fn hex_str_to_bytes_left_pads_odd_length_input() {
+        assert_eq!(hex_str_to_bytes("abc"), vec![0x0a, 0xbc]);
+        assert_eq!(hex_str_to_bytes("0xa"), vec![0x0a]);
+    }
+
+    #[test]
+    fn hex_str_to_bytes_rejects_empty_and_unparseable_input() {
+        assert_eq!(hex_str_to_bytes(""), Vec::<u8>::new());
+        assert_eq!(hex_str_to_bytes("0x"), Vec::<u8>::new());
+        assert_eq!(hex_str_to_bytes("zz"), Vec::<u8>::new());
+    }
+
+    #[test]
+    fn hex_str_to_bytes_round_trips_through_bytes_to_hex_string() {
+        let bytes = vec![0x10, 0xde, 0x01, 0x81];
+        assert_eq!(hex_str_to_bytes(&bytes_to_hex_string(&bytes)), bytes);
+    }
+
+    #[test]
+    fn address_to_pci_id_splits_bus_and_device() {
+        assert_eq!(address_to_pci_id("0000:00:02.0"), 0x0002);
+        assert_eq!(address_to_pci_id("0000:3a:1f.7"), 0x3a1f);
+    }
+
+    #[test]
+    fn address_to_pci_id_accepts_a_bare_bdf_address() {
+        assert_eq!(address_to_pci_id("00:02.0"), 0x0002);
+    }
+
+    #[test]
+    fn address_to_pci_id_falls_back_to_zero_on_malformed_input() {
+        assert_eq!(address_to_pci_id(""), 0);
+        assert_eq!(address_to_pci_id("not-an-address"), 0);
+    }
+}
@@ -16,6 +16,19 @@ cfg_if::cfg_if! {
     }
 }
 
+/// One of a `PCIDevice`'s six Base Address Registers (BARs), decoded from
+/// the `resource` file `sysfs` exposes for the device.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BaseAddressRegister {
+    /// The BAR's base address, or `0` if the BAR is unused.
+    pub base_address: u64,
+    /// The size of the region the BAR decodes, in bytes.
+    pub size: u64,
+    /// `true` if the BAR maps memory (MMIO), `false` if it's port I/O.
+    pub is_memory: bool,
+}
+
 pub trait Properties: private::PrivateProperties {
         /// This function returns a new instance of `PCIDevice` struct using the given `path`.
         ///
@@ -34,6 +47,72 @@ pub trait Properties: private::PrivateProperties {
         /// let device_3 = PCIDevice::new("/sys/bus/pci/devices/0000:00:02.0");
         /// ```
         fn new(path: &str) -> Self;
+
+        // Getters that aren't part of `init()`'s original field set live here
+        // (rather than on the crate-private `PrivateProperties`) so external
+        // crates can actually call them.
+
+        /// This function returns the `PCIDevice` subclass name.
+        fn subclass_name(&self) -> String;
+
+        /// This function returns the `PCIDevice` programming interface name.
+        fn prog_if_name(&self) -> String;
+
+        /// This function returns the name of the kernel driver currently
+        /// bound to the `PCIDevice`, or an empty `String` if it is unbound.
+        fn driver(&self) -> String;
+
+        /// This function returns the `PCIDevice` modalias, used by the
+        /// kernel to match it against a driver's module alias table.
+        fn modalias(&self) -> String;
+
+        /// This function returns the `PCIDevice`'s six Base Address Registers.
+        fn bars(&self) -> [BaseAddressRegister; 6];
+
+        /// This function returns the `PCIDevice` interrupt line.
+        fn interrupt_line(&self) -> u8;
+
+        /// This function returns the `PCIDevice` interrupt pin.
+        fn interrupt_pin(&self) -> u8;
+
+        /// This function returns the `PCIDevice` programming interface.
+        fn prog_if(&self) -> u8;
+
+        /// This function returns the `PCIDevice` header type.
+        fn header_type(&self) -> u8;
+
+        // Typed ID accessors, derived from `PrivateProperties`'s `Vec<u8>` getters...
+
+        /// This function returns the `PCIDevice` vendor ID, parsed as a `u16`.
+        fn vendor_id_u16(&self) -> u16 {
+            crate::extra::bytes_to_u16(&self.vendor_id())
+        }
+
+        /// This function returns the `PCIDevice` device ID, parsed as a `u16`.
+        fn device_id_u16(&self) -> u16 {
+            crate::extra::bytes_to_u16(&self.device_id())
+        }
+
+        /// This function returns the `PCIDevice` subsystem vendor ID, parsed as a `u16`.
+        fn subsystem_vendor_id_u16(&self) -> u16 {
+            crate::extra::bytes_to_u16(&self.subsystem_vendor_id())
+        }
+
+        /// This function returns the `PCIDevice` subsystem device ID, parsed as a `u16`.
+        fn subsystem_device_id_u16(&self) -> u16 {
+            crate::extra::bytes_to_u16(&self.subsystem_device_id())
+        }
+
+        /// This function returns the `PCIDevice` class ID, parsed as a `u32`.
+        fn class_id_u32(&self) -> u32 {
+            crate::extra::bytes_to_u32(&self.class_id())
+        }
+
+        /// This function returns the `(bus << 8) | device` identifier used
+        /// by GPU-compute tooling to key a device from its `bb:dd.f` address.
+        fn pci_id(&self) -> u16 {
+            crate::extra::address_to_pci_id(&self.address())
+        }
 }
 
 pub(crate) mod private {
@@ -128,6 +207,14 @@ pub(crate) mod private {
         #[doc(hidden)]
         fn set_class_name(&mut self);
 
+        /// This function sets the `subclass_name` field of the `PCIDevice`.
+        #[doc(hidden)]
+        fn set_subclass_name(&mut self);
+
+        /// This function sets the `prog_if_name` field of the `PCIDevice`.
+        #[doc(hidden)]
+        fn set_prog_if_name(&mut self);
+
         /// This function sets the `revision` field of the `PCIDevice`.
         #[doc(hidden)]
         fn set_revision(&mut self);
@@ -159,9 +246,65 @@ pub(crate) mod private {
         /// This function sets the `subsystem_name` field of the `PCIDevice`.
         #[doc(hidden)]
         fn set_subsystem_name(&mut self);
+
+        /// This function sets the `bars` field of the `PCIDevice`.
+        #[doc(hidden)]
+        fn set_bars(&mut self);
+
+        /// This function sets the `interrupt_line` field of the `PCIDevice`.
+        #[doc(hidden)]
+        fn set_interrupt_line(&mut self);
+
+        /// This function sets the `interrupt_pin` field of the `PCIDevice`.
+        #[doc(hidden)]
+        fn set_interrupt_pin(&mut self);
+
+        /// This function sets the `prog_if` field of the `PCIDevice`.
+        #[doc(hidden)]
+        fn set_prog_if(&mut self);
+
+        /// This function sets the `header_type` field of the `PCIDevice`.
+        #[doc(hidden)]
+        fn set_header_type(&mut self);
+
+        /// This function sets the `driver` field of the `PCIDevice`.
+        #[doc(hidden)]
+        fn set_driver(&mut self);
+
+        /// This function sets the `modalias` field of the `PCIDevice`.
+        #[doc(hidden)]
+        fn set_modalias(&mut self);
     }
 }
 
+/// A single entry of a `pci_device_id`-style match table, used with
+/// [`Fetch::fetch_matching`] to declaratively claim devices the way a PCI
+/// driver does.
+///
+/// Every field is optional; `None` acts as a wildcard, matching any value.
+/// A device matches an entry if every `Some` field in it equals the
+/// device's corresponding value.
+///
+/// ## Examples
+///
+/// ```
+/// use aparato::DeviceMatch;
+///
+/// // All NVIDIA devices.
+/// let nvidia_gpus = DeviceMatch {
+///     vendor_id: Some(vec![0x10, 0xde]),
+///     ..Default::default()
+/// };
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DeviceMatch {
+    pub vendor_id: Option<Vec<u8>>,
+    pub device_id: Option<Vec<u8>>,
+    pub subsystem_vendor_id: Option<Vec<u8>>,
+    pub subsystem_device_id: Option<Vec<u8>>,
+    pub class_id: Option<Vec<u8>>,
+}
+
 pub trait Fetch {
     /// This function returns a list of available PCI devices and their information.
     fn fetch() -> Vec<PCIDevice>;
@@ -176,7 +319,28 @@ pub trait Fetch {
     /// - `TU117M [GeForce GTX 1650 Mobile / Max-Q]` becomes `GeForce GTX 1650 Mobile / Max-Q`
     /// - `NVIDIA Corporation` becomes `NVIDIA`
     fn fetch_gpus() -> Vec<PCIDevice>;
+
+    /// This function returns every device that matches at least one entry
+    /// of `table`, generalizing `fetch_by_class` and `fetch_gpus` into a
+    /// single declarative filter.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use aparato::{DeviceMatch, Fetch, PCIDevice};
+    ///
+    /// // All devices from vendor 0x8086 with subsystem vendor 0x17aa.
+    /// let table = [DeviceMatch {
+    ///     vendor_id: Some(vec![0x80, 0x86]),
+    ///     subsystem_vendor_id: Some(vec![0x17, 0xaa]),
+    ///     ..Default::default()
+    /// }];
+    /// let devices = PCIDevice::fetch_matching(&table);
+    /// ```
+    fn fetch_matching(table: &[DeviceMatch]) -> Vec<PCIDevice>;
 }
 
-mod classes;
-mod extra;
\ No newline at end of file
+pub mod classes;
+mod extra;
+#[cfg(feature = "serde")]
+mod serde_support;
\ No newline at end of file
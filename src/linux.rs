@@ -0,0 +1,489 @@
+//! The Linux backend, backed entirely by `/sys/bus/pci/devices`.
+
+use crate::private::PrivateProperties;
+use crate::{classes, classes::DeviceClass, extra, BaseAddressRegister, DeviceMatch, Fetch, Properties};
+use std::fs;
+use std::path::PathBuf;
+
+/// A PCI device as described by `/sys/bus/pci/devices/<address>` on Linux.
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LinuxPCIDevice {
+    #[cfg_attr(feature = "serde", serde(skip))]
+    path: PathBuf,
+    address: String,
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_support::serialize_hex",
+            deserialize_with = "crate::serde_support::deserialize_hex"
+        )
+    )]
+    class_id: Vec<u8>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_support::serialize_hex",
+            deserialize_with = "crate::serde_support::deserialize_hex"
+        )
+    )]
+    vendor_id: Vec<u8>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_support::serialize_hex",
+            deserialize_with = "crate::serde_support::deserialize_hex"
+        )
+    )]
+    device_id: Vec<u8>,
+    numa_node: isize,
+    class_name: String,
+    subclass_name: String,
+    prog_if_name: String,
+    vendor_name: String,
+    device_name: String,
+    enabled: bool,
+    d3cold_allowed: bool,
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_support::serialize_hex",
+            deserialize_with = "crate::serde_support::deserialize_hex"
+        )
+    )]
+    revision: Vec<u8>,
+    subsystem_name: String,
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_support::serialize_hex",
+            deserialize_with = "crate::serde_support::deserialize_hex"
+        )
+    )]
+    subsystem_vendor_id: Vec<u8>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_support::serialize_hex",
+            deserialize_with = "crate::serde_support::deserialize_hex"
+        )
+    )]
+    subsystem_device_id: Vec<u8>,
+    bars: [BaseAddressRegister; 6],
+    interrupt_line: u8,
+    interrupt_pin: u8,
+    prog_if: u8,
+    header_type: u8,
+    driver: String,
+    modalias: String,
+}
+
+/// Turns a bare address (`"00:02.0"`), a domain-qualified address
+/// (`"0000:00:02.0"`) or a full `sysfs` path into the `sysfs` path of the
+/// device, so `PCIDevice::new()` can accept any of the three.
+fn normalize_path(path: &str) -> PathBuf {
+    if path.starts_with("/sys") {
+        return PathBuf::from(path);
+    }
+
+    let address = if path.matches(':').count() == 1 {
+        format!("0000:{}", path)
+    } else {
+        path.to_string()
+    };
+
+    PathBuf::from(format!("/sys/bus/pci/devices/{}", address))
+}
+
+impl Properties for LinuxPCIDevice {
+    fn new(path: &str) -> Self {
+        let mut device = Self::default();
+        device.set_path(normalize_path(path));
+        device.init();
+        device
+    }
+
+    fn subclass_name(&self) -> String {
+        self.subclass_name.clone()
+    }
+
+    fn prog_if_name(&self) -> String {
+        self.prog_if_name.clone()
+    }
+
+    fn bars(&self) -> [BaseAddressRegister; 6] {
+        self.bars
+    }
+
+    fn interrupt_line(&self) -> u8 {
+        self.interrupt_line
+    }
+
+    fn interrupt_pin(&self) -> u8 {
+        self.interrupt_pin
+    }
+
+    fn prog_if(&self) -> u8 {
+        self.prog_if
+    }
+
+    fn header_type(&self) -> u8 {
+        self.header_type
+    }
+
+    fn driver(&self) -> String {
+        self.driver.clone()
+    }
+
+    fn modalias(&self) -> String {
+        self.modalias.clone()
+    }
+}
+
+impl PrivateProperties for LinuxPCIDevice {
+    fn init(&mut self) {
+        self.set_address();
+        self.set_class_id();
+        self.set_vendor_id();
+        self.set_device_id();
+        self.set_class_name();
+        self.set_subclass_name();
+        self.set_prog_if_name();
+        self.set_numa_node();
+        self.set_revision();
+        self.set_enabled();
+        self.set_d3cold_allowed();
+        self.set_vendor_name();
+        self.set_device_name();
+        self.set_subsystem_vendor_id();
+        self.set_subsystem_device_id();
+        self.set_subsystem_name();
+        self.set_bars();
+        self.set_interrupt_line();
+        self.set_interrupt_pin();
+        self.set_prog_if();
+        self.set_header_type();
+        self.set_driver();
+        self.set_modalias();
+    }
+
+    fn path(&self) -> PathBuf {
+        self.path.clone()
+    }
+
+    fn address(&self) -> String {
+        self.address.clone()
+    }
+
+    fn class_id(&self) -> Vec<u8> {
+        self.class_id.clone()
+    }
+
+    fn vendor_id(&self) -> Vec<u8> {
+        self.vendor_id.clone()
+    }
+
+    fn device_id(&self) -> Vec<u8> {
+        self.device_id.clone()
+    }
+
+    fn numa_node(&self) -> isize {
+        self.numa_node
+    }
+
+    fn class_name(&self) -> String {
+        self.class_name.clone()
+    }
+
+    fn vendor_name(&self) -> String {
+        self.vendor_name.clone()
+    }
+
+    fn device_name(&self) -> String {
+        self.device_name.clone()
+    }
+
+    fn enabled(&self) -> bool {
+        self.enabled
+    }
+
+    fn d3cold_allowed(&self) -> bool {
+        self.d3cold_allowed
+    }
+
+    fn revision(&self) -> Vec<u8> {
+        self.revision.clone()
+    }
+
+    fn subsystem_name(&self) -> String {
+        self.subsystem_name.clone()
+    }
+
+    fn subsystem_vendor_id(&self) -> Vec<u8> {
+        self.subsystem_vendor_id.clone()
+    }
+
+    fn subsystem_device_id(&self) -> Vec<u8> {
+        self.subsystem_device_id.clone()
+    }
+
+    fn set_path(&mut self, p: PathBuf) {
+        self.path = p;
+    }
+
+    fn set_address(&mut self) {
+        self.address = self
+            .path
+            .file_name()
+            .and_then(|s| s.to_str())
+            .unwrap_or_default()
+            .trim_start_matches("0000:")
+            .to_string();
+    }
+
+    fn set_class_id(&mut self) {
+        self.class_id = extra::hex_str_to_bytes(&extra::read_attribute(&self.path.join("class")));
+    }
+
+    fn set_vendor_id(&mut self) {
+        self.vendor_id = extra::hex_str_to_bytes(&extra::read_attribute(&self.path.join("vendor")));
+    }
+
+    fn set_device_id(&mut self) {
+        self.device_id = extra::hex_str_to_bytes(&extra::read_attribute(&self.path.join("device")));
+    }
+
+    fn set_numa_node(&mut self) {
+        self.numa_node = extra::read_attribute(&self.path.join("numa_node"))
+            .parse()
+            .unwrap_or(-1);
+    }
+
+    fn set_class_name(&mut self) {
+        self.class_name = self
+            .class_id
+            .first()
+            .map(|&id| classes::class_name(id))
+            .unwrap_or_default();
+    }
+
+    fn set_subclass_name(&mut self) {
+        self.subclass_name = match (self.class_id.first(), self.class_id.get(1)) {
+            (Some(&class), Some(&subclass)) => classes::subclass_name(class, subclass),
+            _ => String::new(),
+        };
+    }
+
+    fn set_prog_if_name(&mut self) {
+        self.prog_if_name = match (self.class_id.first(), self.class_id.get(1), self.class_id.get(2)) {
+            (Some(&class), Some(&subclass), Some(&prog_if)) => {
+                classes::prog_if_name(class, subclass, prog_if)
+            }
+            _ => String::new(),
+        };
+    }
+
+    fn set_revision(&mut self) {
+        self.revision = extra::hex_str_to_bytes(&extra::read_attribute(&self.path.join("revision")));
+    }
+
+    fn set_enabled(&mut self) {
+        self.enabled = extra::read_attribute(&self.path.join("enable")) == "1";
+    }
+
+    fn set_d3cold_allowed(&mut self) {
+        self.d3cold_allowed = extra::read_attribute(&self.path.join("d3cold_allowed")) == "1";
+    }
+
+    fn set_vendor_name(&mut self) {
+        self.vendor_name = extra::vendor_name(&self.vendor_id);
+    }
+
+    fn set_device_name(&mut self) {
+        self.device_name = extra::device_name(&self.vendor_id, &self.device_id);
+    }
+
+    fn set_subsystem_device_id(&mut self) {
+        self.subsystem_device_id =
+            extra::hex_str_to_bytes(&extra::read_attribute(&self.path.join("subsystem_device")));
+    }
+
+    fn set_subsystem_vendor_id(&mut self) {
+        self.subsystem_vendor_id =
+            extra::hex_str_to_bytes(&extra::read_attribute(&self.path.join("subsystem_vendor")));
+    }
+
+    fn set_subsystem_name(&mut self) {
+        self.subsystem_name = extra::vendor_name(&self.subsystem_vendor_id);
+    }
+
+    fn set_bars(&mut self) {
+        let mut bars = [BaseAddressRegister::default(); 6];
+
+        for (bar, line) in bars.iter_mut().zip(extra::read_attribute(&self.path.join("resource")).lines()) {
+            let mut fields = line.split_whitespace().map(|field| {
+                u64::from_str_radix(field.trim_start_matches("0x"), 16).unwrap_or(0)
+            });
+            let start = fields.next().unwrap_or(0);
+            let end = fields.next().unwrap_or(0);
+            let flags = fields.next().unwrap_or(0);
+
+            *bar = BaseAddressRegister {
+                base_address: start,
+                size: if start == 0 && end == 0 { 0 } else { end.saturating_sub(start) + 1 },
+                is_memory: flags & 1 == 0,
+            };
+        }
+
+        self.bars = bars;
+    }
+
+    fn set_interrupt_line(&mut self) {
+        self.interrupt_line = self.config_byte(0x3C);
+    }
+
+    fn set_interrupt_pin(&mut self) {
+        self.interrupt_pin = self.config_byte(0x3D);
+    }
+
+    fn set_prog_if(&mut self) {
+        self.prog_if = self.config_byte(0x09);
+    }
+
+    fn set_header_type(&mut self) {
+        self.header_type = self.config_byte(0x0E);
+    }
+
+    fn set_driver(&mut self) {
+        self.driver = fs::read_link(self.path.join("driver"))
+            .ok()
+            .and_then(|link| link.file_name().map(|name| name.to_string_lossy().into_owned()))
+            .unwrap_or_default();
+    }
+
+    fn set_modalias(&mut self) {
+        self.modalias = extra::read_attribute(&self.path.join("modalias"));
+    }
+}
+
+impl LinuxPCIDevice {
+    /// Reads a single byte at `offset` out of the device's `config` file,
+    /// the raw PCI configuration space `sysfs` exposes. Returns `0` if the
+    /// file is too short to contain `offset` or can't be read.
+    fn config_byte(&self, offset: usize) -> u8 {
+        fs::read(self.path.join("config"))
+            .ok()
+            .and_then(|bytes| bytes.get(offset).copied())
+            .unwrap_or(0)
+    }
+}
+
+impl Fetch for LinuxPCIDevice {
+    fn fetch() -> Vec<crate::PCIDevice> {
+        let Ok(entries) = fs::read_dir("/sys/bus/pci/devices") else {
+            return Vec::new();
+        };
+
+        let mut devices: Vec<crate::PCIDevice> = entries
+            .filter_map(|entry| entry.ok())
+            .map(|entry| crate::PCIDevice::new(&entry.path().display().to_string()))
+            .collect();
+
+        devices.sort_by_key(|device| device.address());
+        devices
+    }
+
+    fn fetch_by_class(class: DeviceClass) -> Vec<crate::PCIDevice> {
+        Self::fetch()
+            .into_iter()
+            .filter(|device| device.class_id().first() == Some(&class.id()))
+            .collect()
+    }
+
+    fn fetch_gpus() -> Vec<crate::PCIDevice> {
+        Self::fetch_by_class(DeviceClass::DisplayController)
+            .into_iter()
+            .map(|mut device| {
+                device.vendor_name = extra::mask_vendor_name(&device.vendor_name);
+                device.device_name = extra::mask_device_name(&device.device_name);
+                device
+            })
+            .collect()
+    }
+
+    fn fetch_matching(table: &[DeviceMatch]) -> Vec<crate::PCIDevice> {
+        Self::fetch()
+            .into_iter()
+            .filter(|device| table.iter().any(|entry| device_matches(device, entry)))
+            .collect()
+    }
+}
+
+/// Returns whether `device` satisfies every `Some` field of `entry`.
+fn device_matches(device: &LinuxPCIDevice, entry: &DeviceMatch) -> bool {
+    entry.vendor_id.as_ref().is_none_or(|id| id == &device.vendor_id)
+        && entry.device_id.as_ref().is_none_or(|id| id == &device.device_id)
+        && entry
+            .subsystem_vendor_id
+            .as_ref()
+            .is_none_or(|id| id == &device.subsystem_vendor_id)
+        && entry
+            .subsystem_device_id
+            .as_ref()
+            .is_none_or(|id| id == &device.subsystem_device_id)
+        && entry.class_id.as_ref().is_none_or(|id| id == &device.class_id)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Writes `resource` (a `resource`-file body) to a fresh scratch
+    /// directory and returns a `LinuxPCIDevice` pointed at it, so
+    /// `set_bars()` can be exercised without a real `sysfs` tree.
+    fn device_with_resource(resource: &str) -> LinuxPCIDevice {
+        let dir = std::env::temp_dir().join(format!("aparato-test-{:p}", resource.as_ptr()));
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("resource"), resource).unwrap();
+
+        let mut device = LinuxPCIDevice::default();
+        device.set_path(dir);
+        device
+    }
+
+    #[test]
+    fn set_bars_decodes_size_and_memory_flag() {
+        let mut device = device_with_resource(
+            "0x000000000f000000 0x000000000fffffff 0x0000000000040200\n\
+             0x0000000000001000 0x000000000000107f 0x0000000000000101\n",
+        );
+        device.set_bars();
+
+        assert_eq!(device.bars[0].base_address, 0x0f000000);
+        assert_eq!(device.bars[0].size, 0x01000000);
+        assert!(device.bars[0].is_memory);
+
+        assert_eq!(device.bars[1].base_address, 0x1000);
+        assert_eq!(device.bars[1].size, 0x80);
+        assert!(!device.bars[1].is_memory);
+    }
+
+    #[test]
+    fn set_bars_treats_an_all_zero_line_as_an_unused_bar() {
+        let mut device = device_with_resource("0x0000000000000000 0x0000000000000000 0x0000000000000000\n");
+        device.set_bars();
+
+        assert_eq!(device.bars[0].base_address, 0);
+        assert_eq!(device.bars[0].size, 0);
+    }
+
+    #[test]
+    fn set_bars_does_not_underflow_when_end_is_before_start() {
+        // Malformed/truncated `resource` line: shouldn't be possible on a
+        // real system, but must not panic on debug builds.
+        let mut device = device_with_resource("0x0000000000001000 0x0000000000000fff 0x0000000000000000\n");
+        device.set_bars();
+
+        assert_eq!(device.bars[0].size, 1);
+    }
+}
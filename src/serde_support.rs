@@ -0,0 +1,26 @@
+//! `serde(serialize_with/deserialize_with = "...")` helpers that render the `Vec<u8>` ID fields
+//! (`vendor_id`, `device_id`, `class_id`, `revision`, `subsystem_vendor_id`,
+//! `subsystem_device_id`) as canonical lowercase hex strings instead of
+//! byte arrays, matching how `lspci` and `pci.ids` present them.
+#![cfg(feature = "serde")]
+
+use serde::{Deserialize, Deserializer, Serializer};
+
+/// Serializes a PCI ID's raw bytes as a lowercase hex string, e.g.
+/// `[0x10, 0xde]` becomes `"10de"`.
+pub(crate) fn serialize_hex<S>(bytes: &[u8], serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    serializer.serialize_str(&crate::extra::bytes_to_hex_string(bytes))
+}
+
+/// Parses a lowercase hex string back into a PCI ID's raw bytes, e.g.
+/// `"10de"` becomes `vec![0x10, 0xde]`.
+pub(crate) fn deserialize_hex<'de, D>(deserializer: D) -> Result<Vec<u8>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let s = String::deserialize(deserializer)?;
+    Ok(crate::extra::hex_str_to_bytes(&s))
+}
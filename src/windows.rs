@@ -0,0 +1,185 @@
+//! Stub Windows backend. Windows exposes PCI topology through `SetupAPI`/WMI
+//! rather than a `sysfs`-like tree, which aparato doesn't speak to yet, so
+//! only `path` and `address` are filled in, every other getter returns an
+//! empty, zero or `false` value (`-1` for `numa_node()`), and `Fetch`
+//! returns no devices.
+
+use crate::private::PrivateProperties;
+use crate::{classes::DeviceClass, BaseAddressRegister, DeviceMatch, Fetch, Properties};
+use std::path::PathBuf;
+
+/// A PCI device on Windows. Not yet implemented beyond the `path`/`address`
+/// bookkeeping needed to construct one.
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct WindowsPCIDevice {
+    #[cfg_attr(feature = "serde", serde(skip))]
+    path: PathBuf,
+    address: String,
+}
+
+impl Properties for WindowsPCIDevice {
+    fn new(path: &str) -> Self {
+        let mut device = Self::default();
+        device.set_path(PathBuf::from(path));
+        device.init();
+        device
+    }
+
+    fn subclass_name(&self) -> String {
+        String::new()
+    }
+
+    fn prog_if_name(&self) -> String {
+        String::new()
+    }
+
+    fn bars(&self) -> [BaseAddressRegister; 6] {
+        [BaseAddressRegister::default(); 6]
+    }
+
+    fn interrupt_line(&self) -> u8 {
+        0
+    }
+
+    fn interrupt_pin(&self) -> u8 {
+        0
+    }
+
+    fn prog_if(&self) -> u8 {
+        0
+    }
+
+    fn header_type(&self) -> u8 {
+        0
+    }
+
+    fn driver(&self) -> String {
+        String::new()
+    }
+
+    fn modalias(&self) -> String {
+        String::new()
+    }
+}
+
+impl PrivateProperties for WindowsPCIDevice {
+    fn init(&mut self) {
+        self.set_address();
+    }
+
+    fn path(&self) -> PathBuf {
+        self.path.clone()
+    }
+
+    fn address(&self) -> String {
+        self.address.clone()
+    }
+
+    fn class_id(&self) -> Vec<u8> {
+        Vec::new()
+    }
+
+    fn vendor_id(&self) -> Vec<u8> {
+        Vec::new()
+    }
+
+    fn device_id(&self) -> Vec<u8> {
+        Vec::new()
+    }
+
+    fn numa_node(&self) -> isize {
+        -1
+    }
+
+    fn class_name(&self) -> String {
+        String::new()
+    }
+
+    fn vendor_name(&self) -> String {
+        String::new()
+    }
+
+    fn device_name(&self) -> String {
+        String::new()
+    }
+
+    fn enabled(&self) -> bool {
+        false
+    }
+
+    fn d3cold_allowed(&self) -> bool {
+        false
+    }
+
+    fn revision(&self) -> Vec<u8> {
+        Vec::new()
+    }
+
+    fn subsystem_name(&self) -> String {
+        String::new()
+    }
+
+    fn subsystem_vendor_id(&self) -> Vec<u8> {
+        Vec::new()
+    }
+
+    fn subsystem_device_id(&self) -> Vec<u8> {
+        Vec::new()
+    }
+
+    fn set_path(&mut self, p: PathBuf) {
+        self.path = p;
+    }
+
+    fn set_address(&mut self) {
+        self.address = self
+            .path
+            .file_name()
+            .and_then(|s| s.to_str())
+            .unwrap_or_default()
+            .to_string();
+    }
+
+    fn set_class_id(&mut self) {}
+    fn set_vendor_id(&mut self) {}
+    fn set_device_id(&mut self) {}
+    fn set_numa_node(&mut self) {}
+    fn set_class_name(&mut self) {}
+    fn set_subclass_name(&mut self) {}
+    fn set_prog_if_name(&mut self) {}
+    fn set_revision(&mut self) {}
+    fn set_enabled(&mut self) {}
+    fn set_d3cold_allowed(&mut self) {}
+    fn set_vendor_name(&mut self) {}
+    fn set_device_name(&mut self) {}
+    fn set_subsystem_device_id(&mut self) {}
+    fn set_subsystem_vendor_id(&mut self) {}
+    fn set_subsystem_name(&mut self) {}
+
+    fn set_bars(&mut self) {}
+    fn set_interrupt_line(&mut self) {}
+    fn set_interrupt_pin(&mut self) {}
+    fn set_prog_if(&mut self) {}
+    fn set_header_type(&mut self) {}
+    fn set_driver(&mut self) {}
+    fn set_modalias(&mut self) {}
+}
+
+impl Fetch for WindowsPCIDevice {
+    fn fetch() -> Vec<crate::PCIDevice> {
+        Vec::new()
+    }
+
+    fn fetch_by_class(_class: DeviceClass) -> Vec<crate::PCIDevice> {
+        Vec::new()
+    }
+
+    fn fetch_gpus() -> Vec<crate::PCIDevice> {
+        Vec::new()
+    }
+
+    fn fetch_matching(_table: &[DeviceMatch]) -> Vec<crate::PCIDevice> {
+        Vec::new()
+    }
+}